@@ -0,0 +1,332 @@
+//! Parallel collection into existing containers (`collect_into_vec`,
+//! `unzip_into_vecs`), plus the shared `unindexed()` helper that
+//! `from_par_iter` and `extend` build on for iterators whose length isn't
+//! known exactly ahead of time (`filter`, `filter_map`, `flat_map`, and the
+//! like).
+
+use super::plumbing::*;
+use super::*;
+use std::cmp;
+use std::collections::LinkedList;
+use std::marker::PhantomData;
+
+/// Collects the items of an indexed parallel iterator into `v`, truncating
+/// it first. Since the iterator's length is known exactly, the full
+/// capacity can be reserved up front and every leaf buffer is sized
+/// exactly. Sibling buffers are still concatenated at each join, which
+/// grows (and may reallocate) the left one.
+pub fn collect_into_vec<I>(pi: I, v: &mut Vec<I::Item>)
+    where I: IndexedParallelIterator
+{
+    v.truncate(0);
+    let len = pi.len();
+    v.reserve(len);
+
+    struct Callback<'v, T: 'v> {
+        vec: &'v mut Vec<T>,
+        len: usize,
+    }
+
+    impl<'v, T: Send + 'v> ProducerCallback<T> for Callback<'v, T> {
+        type Output = ();
+
+        fn callback<P>(self, producer: P)
+            where P: Producer<Item = T>
+        {
+            let block_size = leaf_len(self.len);
+            self.vec.extend(collect_producer(producer, self.len, block_size));
+        }
+    }
+
+    pi.with_producer(Callback { vec: v, len: len });
+}
+
+/// Unzips the items of an indexed parallel iterator of pairs into `left`
+/// and `right`, truncating both first. Like `collect_into_vec`, the exact
+/// length lets both targets and every leaf buffer be reserved up front;
+/// sibling buffers are concatenated at each join.
+pub fn unzip_into_vecs<I, A, B>(pi: I, left: &mut Vec<A>, right: &mut Vec<B>)
+    where I: IndexedParallelIterator<Item = (A, B)>,
+          A: Send,
+          B: Send
+{
+    left.truncate(0);
+    right.truncate(0);
+    let len = pi.len();
+    left.reserve(len);
+    right.reserve(len);
+
+    struct Callback<'l, 'r, A: 'l, B: 'r> {
+        left: &'l mut Vec<A>,
+        right: &'r mut Vec<B>,
+        len: usize,
+    }
+
+    impl<'l, 'r, A: Send + 'l, B: Send + 'r> ProducerCallback<(A, B)> for Callback<'l, 'r, A, B> {
+        type Output = ();
+
+        fn callback<P>(self, producer: P)
+            where P: Producer<Item = (A, B)>
+        {
+            let block_size = leaf_len(self.len);
+            let (l, r) = unzip_producer(producer, self.len, block_size);
+            self.left.extend(l);
+            self.right.extend(r);
+        }
+    }
+
+    pi.with_producer(Callback { left: left, right: right, len: len });
+}
+
+/// The exact-length threshold below which a producer is drained
+/// sequentially rather than split further; splitting past this point just
+/// adds `join` overhead without exposing any more usable parallelism.
+fn leaf_len(len: usize) -> usize {
+    cmp::max(1, len / (::rayon_core::current_num_threads() * 4))
+}
+
+fn collect_producer<P>(producer: P, len: usize, block_size: usize) -> Vec<P::Item>
+    where P: Producer
+{
+    if len <= block_size {
+        let mut v = Vec::with_capacity(len);
+        v.extend(producer.into_iter());
+        return v;
+    }
+
+    let mid = len / 2;
+    let (left, right) = producer.split_at(mid);
+    let (mut left_vec, right_vec) =
+        ::rayon_core::join(|| collect_producer(left, mid, block_size),
+                            || collect_producer(right, len - mid, block_size));
+    left_vec.extend(right_vec);
+    left_vec
+}
+
+fn unzip_producer<P, A, B>(producer: P, len: usize, block_size: usize) -> (Vec<A>, Vec<B>)
+    where P: Producer<Item = (A, B)>,
+          A: Send,
+          B: Send
+{
+    if len <= block_size {
+        let mut l = Vec::with_capacity(len);
+        let mut r = Vec::with_capacity(len);
+        for (a, b) in producer.into_iter() {
+            l.push(a);
+            r.push(b);
+        }
+        return (l, r);
+    }
+
+    let mid = len / 2;
+    let (left, right) = producer.split_at(mid);
+    let ((mut ll, mut lr), (rl, rr)) =
+        ::rayon_core::join(|| unzip_producer(left, mid, block_size),
+                            || unzip_producer(right, len - mid, block_size));
+    ll.extend(rl);
+    lr.extend(rr);
+    (ll, lr)
+}
+
+/// Drives an arbitrary (possibly unindexed) parallel iterator down to a
+/// list of per-job buffers, one `Vec` per leaf of the job tree. Each
+/// buffer is pre-sized from a share of the iterator's length, split in
+/// half at every consumer split.
+///
+/// `opt_len()` is consulted first: a handful of combinators (anything
+/// wrapping an `IndexedParallelIterator`, like `step_by` or `fold_chunks`)
+/// report an exact count there even though they still have to be driven
+/// unindexed here, so their buffers get sized exactly rather than
+/// guessed. Only when `opt_len()` is `None` do we fall back to
+/// `len_hint()`'s advisory lower bound -- combinators like `filter` can't
+/// know their output length ahead of time no matter how this is called,
+/// so that case stays a guess by necessity.
+pub(super) fn unindexed<I>(pi: I) -> LinkedList<Vec<I::Item>>
+    where I: ParallelIterator
+{
+    let capacity_hint = match pi.opt_len() {
+        Some(len) => len,
+        None => pi.len_hint().0,
+    };
+    pi.drive_unindexed(ListConsumer::new(capacity_hint))
+}
+
+struct ListConsumer<T> {
+    capacity_hint: usize,
+    phantom: PhantomData<T>,
+}
+
+impl<T> ListConsumer<T> {
+    fn new(capacity_hint: usize) -> Self {
+        ListConsumer {
+            capacity_hint: capacity_hint,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<T: Send> Consumer<T> for ListConsumer<T> {
+    type Folder = ListFolder<T>;
+    type Reducer = ListReducer;
+    type Result = LinkedList<Vec<T>>;
+
+    fn split_at(self, _index: usize) -> (Self, Self, Self::Reducer) {
+        let left_hint = self.capacity_hint / 2;
+        let right_hint = self.capacity_hint - left_hint;
+        (ListConsumer::new(left_hint), ListConsumer::new(right_hint), ListReducer)
+    }
+
+    fn into_folder(self) -> Self::Folder {
+        ListFolder { vec: Vec::with_capacity(self.capacity_hint) }
+    }
+
+    fn full(&self) -> bool {
+        false
+    }
+}
+
+impl<T: Send> UnindexedConsumer<T> for ListConsumer<T> {
+    fn split_off_left(&self) -> Self {
+        // `&self` can't be shrunk in place, so the sibling this produces
+        // and the continuation of `self` both estimate off of the same
+        // (pre-split) hint rather than a running remainder; still a
+        // reasonable, strictly advisory share of the original estimate.
+        ListConsumer::new(self.capacity_hint / 2)
+    }
+
+    fn to_reducer(&self) -> Self::Reducer {
+        ListReducer
+    }
+}
+
+struct ListFolder<T> {
+    vec: Vec<T>,
+}
+
+impl<T> Folder<T> for ListFolder<T> {
+    type Result = LinkedList<Vec<T>>;
+
+    fn consume(mut self, item: T) -> Self {
+        self.vec.push(item);
+        self
+    }
+
+    fn consume_iter<I>(mut self, iter: I) -> Self
+        where I: IntoIterator<Item = T>
+    {
+        self.vec.extend(iter);
+        self
+    }
+
+    fn complete(self) -> Self::Result {
+        let mut list = LinkedList::new();
+        if !self.vec.is_empty() {
+            list.push_back(self.vec);
+        }
+        list
+    }
+
+    fn full(&self) -> bool {
+        false
+    }
+}
+
+struct ListReducer;
+
+impl<T> Reducer<LinkedList<Vec<T>>> for ListReducer {
+    fn reduce(self, mut left: LinkedList<Vec<T>>, mut right: LinkedList<Vec<T>>) -> LinkedList<Vec<T>> {
+        left.append(&mut right);
+        left
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Wraps a `ParallelIterator` to report an arbitrary, possibly wrong,
+    /// `len_hint()` regardless of how many items it actually produces --
+    /// lets tests exercise `unindexed()`'s "the hint is advisory only"
+    /// guarantee directly.
+    struct MisleadingHint<I> {
+        base: I,
+        hint: (usize, Option<usize>),
+    }
+
+    impl<I: ParallelIterator> ParallelIterator for MisleadingHint<I> {
+        type Item = I::Item;
+
+        fn drive_unindexed<C>(self, consumer: C) -> C::Result
+            where C: UnindexedConsumer<Self::Item>
+        {
+            self.base.drive_unindexed(consumer)
+        }
+
+        fn len_hint(&self) -> (usize, Option<usize>) {
+            self.hint
+        }
+    }
+
+    #[test]
+    fn check_collect_survives_underestimated_hint() {
+        let v: Vec<i32> = (0..50).collect();
+        let misled = MisleadingHint { base: v.clone().into_par_iter(), hint: (0, None) };
+        let result: Vec<i32> = misled.collect();
+        assert_eq!(result, v);
+    }
+
+    #[test]
+    fn check_collect_survives_overestimated_hint() {
+        let v: Vec<i32> = (0..50).collect();
+        let misled = MisleadingHint { base: v.clone().into_par_iter(), hint: (1000, Some(1000)) };
+        let result: Vec<i32> = misled.collect();
+        assert_eq!(result, v);
+    }
+
+    /// Wraps a `ParallelIterator` to report an arbitrary, possibly wrong,
+    /// `opt_len()` -- exercises `unindexed()`'s preference for `opt_len()`
+    /// over `len_hint()`'s lower bound, and confirms a bad value there
+    /// still can't corrupt the result.
+    struct MisleadingOptLen<I> {
+        base: I,
+        opt_len: Option<usize>,
+    }
+
+    impl<I: ParallelIterator> ParallelIterator for MisleadingOptLen<I> {
+        type Item = I::Item;
+
+        fn drive_unindexed<C>(self, consumer: C) -> C::Result
+            where C: UnindexedConsumer<Self::Item>
+        {
+            self.base.drive_unindexed(consumer)
+        }
+
+        fn opt_len(&self) -> Option<usize> {
+            self.opt_len
+        }
+    }
+
+    #[test]
+    fn check_collect_survives_misleading_opt_len() {
+        let v: Vec<i32> = (0..50).collect();
+        let misled = MisleadingOptLen { base: v.clone().into_par_iter(), opt_len: Some(3) };
+        let result: Vec<i32> = misled.collect();
+        assert_eq!(result, v);
+    }
+
+    #[test]
+    fn check_collect_into_vec() {
+        let mut v = vec![99];
+        collect_into_vec((0..5).into_par_iter(), &mut v);
+        assert_eq!(v, vec![0, 1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn check_unzip_into_vecs() {
+        let mut left = vec![];
+        let mut right = vec![];
+        unzip_into_vecs(vec![(1, 'a'), (2, 'b')].into_par_iter(), &mut left, &mut right);
+        assert_eq!(left, vec![1, 2]);
+        assert_eq!(right, vec!['a', 'b']);
+    }
+}
@@ -0,0 +1,82 @@
+use super::plumbing::*;
+use super::*;
+
+/// `Copied` is an iterator that copies the elements of an underlying
+/// iterator over `&T`. This struct is created by the [`copied()`] method on
+/// [`ParallelIterator`].
+///
+/// [`copied()`]: trait.ParallelIterator.html#method.copied
+/// [`ParallelIterator`]: trait.ParallelIterator.html
+#[derive(Debug, Clone)]
+pub struct Copied<I> {
+    base: I,
+}
+
+pub fn new<I>(base: I) -> Copied<I> {
+    Copied { base: base }
+}
+
+impl<'a, T, I> ParallelIterator for Copied<I>
+    where I: ParallelIterator<Item = &'a T>,
+          T: 'a + Copy + Send
+{
+    type Item = T;
+
+    fn drive_unindexed<C>(self, consumer: C) -> C::Result
+        where C: UnindexedConsumer<Self::Item>
+    {
+        // `Copy` implies `Clone`, so this is just `cloned()` in disguise --
+        // the `Copy` bound only exists to document intent at the call site.
+        self.base.cloned().drive_unindexed(consumer)
+    }
+
+    fn opt_len(&self) -> Option<usize> {
+        self.base.opt_len()
+    }
+
+    fn len_hint(&self) -> (usize, Option<usize>) {
+        self.base.len_hint()
+    }
+}
+
+impl<'a, T, I> IndexedParallelIterator for Copied<I>
+    where I: IndexedParallelIterator<Item = &'a T>,
+          T: 'a + Copy + Send
+{
+    fn len(&self) -> usize {
+        self.base.len()
+    }
+
+    fn drive<C: Consumer<Self::Item>>(self, consumer: C) -> C::Result {
+        self.base.cloned().drive(consumer)
+    }
+
+    fn with_producer<CB: ProducerCallback<Self::Item>>(self, callback: CB) -> CB::Output {
+        self.base.cloned().with_producer(callback)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn check_copied_empty() {
+        let v: Vec<i32> = vec![];
+        let result: Vec<i32> = v.par_iter().copied().collect();
+        assert_eq!(result, Vec::<i32>::new());
+    }
+
+    #[test]
+    fn check_copied() {
+        let v = vec![1, 2, 3];
+        let result: Vec<i32> = v.par_iter().copied().collect();
+        assert_eq!(result, v);
+    }
+
+    #[test]
+    fn check_copied_len() {
+        let v = vec![1, 2, 3];
+        assert_eq!(v.par_iter().copied().len(), 3);
+    }
+}
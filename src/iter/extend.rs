@@ -0,0 +1,97 @@
+//! `ParallelExtend` impls for the standard collections. Each reserves up
+//! front from the iterator's exact length when it's known (`opt_len()`),
+//! falling back to `len_hint()`'s advisory lower bound otherwise, then
+//! drives it down into per-job buffers via `collect::unindexed()` and
+//! appends them in.
+
+use super::collect::unindexed;
+use super::*;
+use std::collections::HashMap;
+use std::hash::{BuildHasher, Hash};
+
+/// The amount to reserve before driving `par_iter` through `unindexed()`:
+/// the exact length when `opt_len()` knows it, otherwise just the
+/// advisory `len_hint()` lower bound.
+fn reserve_hint<I: ParallelIterator>(par_iter: &I) -> usize {
+    par_iter.opt_len().unwrap_or_else(|| par_iter.len_hint().0)
+}
+
+impl<T: Send> ParallelExtend<T> for Vec<T> {
+    fn par_extend<I>(&mut self, par_iter: I)
+        where I: IntoParallelIterator<Item = T>
+    {
+        let par_iter = par_iter.into_par_iter();
+        self.reserve(reserve_hint(&par_iter));
+        for chunk in unindexed(par_iter) {
+            self.extend(chunk);
+        }
+    }
+}
+
+impl ParallelExtend<char> for String {
+    fn par_extend<I>(&mut self, par_iter: I)
+        where I: IntoParallelIterator<Item = char>
+    {
+        let par_iter = par_iter.into_par_iter();
+        self.reserve(reserve_hint(&par_iter));
+        for chunk in unindexed(par_iter) {
+            self.extend(chunk);
+        }
+    }
+}
+
+impl<K, V, S> ParallelExtend<(K, V)> for HashMap<K, V, S>
+    where K: Eq + Hash + Send,
+          V: Send,
+          S: BuildHasher + Send
+{
+    fn par_extend<I>(&mut self, par_iter: I)
+        where I: IntoParallelIterator<Item = (K, V)>
+    {
+        let par_iter = par_iter.into_par_iter();
+        self.reserve(reserve_hint(&par_iter));
+        for chunk in unindexed(par_iter) {
+            self.extend(chunk);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn check_par_extend_vec_with_underestimated_hint() {
+        // `filter` doesn't override `len_hint()`, so it reports the
+        // default lower bound of `0` -- this exercises `par_extend`'s
+        // capacity reservation against a hint that under-counts badly.
+        let mut v = vec![-1];
+        v.par_extend((0..10).into_par_iter().filter(|&x| x % 2 == 0));
+        assert_eq!(v, vec![-1, 0, 2, 4, 6, 8]);
+    }
+
+    #[test]
+    fn check_par_extend_vec_with_exact_opt_len() {
+        // `step_by` overrides `opt_len()` to its exact output count, so
+        // `reserve_hint` should reserve exactly that much rather than
+        // falling back to an advisory guess.
+        let mut v = vec![-1];
+        v.par_extend((0..10).into_par_iter().step_by(3));
+        assert_eq!(v, vec![-1, 0, 3, 6, 9]);
+    }
+
+    #[test]
+    fn check_par_extend_string() {
+        let mut s = String::from("a");
+        s.par_extend(vec!['b', 'c'].into_par_iter());
+        assert_eq!(s, "abc");
+    }
+
+    #[test]
+    fn check_par_extend_hash_map() {
+        let mut map: HashMap<i32, i32> = HashMap::new();
+        map.par_extend(vec![(1, 10), (2, 20)].into_par_iter());
+        assert_eq!(map.len(), 2);
+        assert_eq!(map.get(&1), Some(&10));
+    }
+}
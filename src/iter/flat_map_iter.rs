@@ -0,0 +1,148 @@
+use super::plumbing::*;
+use super::*;
+
+/// `FlatMapIter` is an iterator that maps each element to a plain, sequential
+/// iterator and then drains those sequential iterators in place of the
+/// original elements. This struct is created by the [`flat_map_iter()`]
+/// method on [`ParallelIterator`].
+///
+/// [`flat_map_iter()`]: trait.ParallelIterator.html#method.flat_map_iter
+/// [`ParallelIterator`]: trait.ParallelIterator.html
+#[derive(Debug, Clone)]
+pub struct FlatMapIter<I, F> {
+    base: I,
+    map_op: F,
+}
+
+pub fn new<I, F>(base: I, map_op: F) -> FlatMapIter<I, F> {
+    FlatMapIter {
+        base: base,
+        map_op: map_op,
+    }
+}
+
+impl<I, F, SI> ParallelIterator for FlatMapIter<I, F>
+    where I: ParallelIterator,
+          F: Fn(I::Item) -> SI + Sync + Send,
+          SI: IntoIterator,
+          SI::Item: Send
+{
+    type Item = SI::Item;
+
+    fn drive_unindexed<C>(self, consumer: C) -> C::Result
+        where C: UnindexedConsumer<Self::Item>
+    {
+        let consumer = FlatMapIterConsumer::new(consumer, &self.map_op);
+        self.base.drive_unindexed(consumer)
+    }
+}
+
+struct FlatMapIterConsumer<'f, C, F: 'f> {
+    base: C,
+    map_op: &'f F,
+}
+
+impl<'f, C, F> FlatMapIterConsumer<'f, C, F> {
+    fn new(base: C, map_op: &'f F) -> Self {
+        FlatMapIterConsumer {
+            base: base,
+            map_op: map_op,
+        }
+    }
+}
+
+impl<'f, T, SI, C, F> Consumer<T> for FlatMapIterConsumer<'f, C, F>
+    where C: UnindexedConsumer<SI::Item>,
+          F: Fn(T) -> SI + Sync,
+          SI: IntoIterator
+{
+    type Folder = FlatMapIterFolder<'f, C::Folder, F>;
+    type Reducer = C::Reducer;
+    type Result = C::Result;
+
+    fn split_at(self, index: usize) -> (Self, Self, Self::Reducer) {
+        let (left, right, reducer) = self.base.split_at(index);
+        (FlatMapIterConsumer::new(left, self.map_op),
+         FlatMapIterConsumer::new(right, self.map_op),
+         reducer)
+    }
+
+    fn into_folder(self) -> Self::Folder {
+        FlatMapIterFolder {
+            base: self.base.into_folder(),
+            map_op: self.map_op,
+        }
+    }
+
+    fn full(&self) -> bool {
+        self.base.full()
+    }
+}
+
+impl<'f, T, SI, C, F> UnindexedConsumer<T> for FlatMapIterConsumer<'f, C, F>
+    where C: UnindexedConsumer<SI::Item>,
+          F: Fn(T) -> SI + Sync,
+          SI: IntoIterator
+{
+    fn split_off_left(&self) -> Self {
+        FlatMapIterConsumer::new(self.base.split_off_left(), self.map_op)
+    }
+
+    fn to_reducer(&self) -> Self::Reducer {
+        self.base.to_reducer()
+    }
+}
+
+struct FlatMapIterFolder<'f, C, F: 'f> {
+    base: C,
+    map_op: &'f F,
+}
+
+impl<'f, T, SI, C, F> Folder<T> for FlatMapIterFolder<'f, C, F>
+    where C: Folder<SI::Item>,
+          F: Fn(T) -> SI + Sync,
+          SI: IntoIterator
+{
+    type Result = C::Result;
+
+    fn consume(self, item: T) -> Self {
+        let map_op = self.map_op;
+        let iter = map_op(item).into_iter();
+        FlatMapIterFolder {
+            base: self.base.consume_iter(iter),
+            map_op: map_op,
+        }
+    }
+
+    fn complete(self) -> Self::Result {
+        self.base.complete()
+    }
+
+    fn full(&self) -> bool {
+        self.base.full()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn check_flat_map_iter_empty() {
+        let v: Vec<i32> = vec![];
+        let result: Vec<i32> = v.into_par_iter().flat_map_iter(|x| 0..x).collect();
+        assert_eq!(result, Vec::<i32>::new());
+    }
+
+    #[test]
+    fn check_flat_map_iter_empty_inner() {
+        let result: Vec<i32> = vec![0, 3, 0].into_par_iter().flat_map_iter(|x| 0..x).collect();
+        assert_eq!(result, vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn check_flat_map_iter_preserves_order() {
+        let result: Vec<i32> = (0..5).into_par_iter().flat_map_iter(|x| vec![x, x]).collect();
+        assert_eq!(result, vec![0, 0, 1, 1, 2, 2, 3, 3, 4, 4]);
+    }
+}
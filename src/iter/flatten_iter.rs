@@ -0,0 +1,62 @@
+use super::plumbing::*;
+use super::*;
+
+/// `FlattenIter` turns an iterator of (plain, sequential) iterable `Item`s
+/// into one flattened parallel iterator, draining each inner sequence
+/// in place rather than treating it as its own parallel iterator. This
+/// struct is created by the [`flatten_iter()`] method on [`ParallelIterator`].
+///
+/// [`flatten_iter()`]: trait.ParallelIterator.html#method.flatten_iter
+/// [`ParallelIterator`]: trait.ParallelIterator.html
+#[derive(Debug, Clone)]
+pub struct FlattenIter<I> {
+    base: I,
+}
+
+pub fn new<I>(base: I) -> FlattenIter<I> {
+    FlattenIter { base: base }
+}
+
+fn identity<T>(x: T) -> T {
+    x
+}
+
+impl<I> ParallelIterator for FlattenIter<I>
+    where I: ParallelIterator,
+          I::Item: IntoIterator,
+          <I::Item as IntoIterator>::Item: Send
+{
+    type Item = <I::Item as IntoIterator>::Item;
+
+    fn drive_unindexed<C>(self, consumer: C) -> C::Result
+        where C: UnindexedConsumer<Self::Item>
+    {
+        self.base.flat_map_iter(identity).drive_unindexed(consumer)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn check_flatten_iter_empty() {
+        let v: Vec<Vec<i32>> = vec![];
+        let result: Vec<i32> = v.into_par_iter().flatten_iter().collect();
+        assert_eq!(result, Vec::<i32>::new());
+    }
+
+    #[test]
+    fn check_flatten_iter_empty_inner() {
+        let v: Vec<Vec<i32>> = vec![vec![], vec![1, 2], vec![]];
+        let result: Vec<i32> = v.into_par_iter().flatten_iter().collect();
+        assert_eq!(result, vec![1, 2]);
+    }
+
+    #[test]
+    fn check_flatten_iter_preserves_order() {
+        let v: Vec<Vec<i32>> = vec![vec![1, 2], vec![3], vec![4, 5]];
+        let result: Vec<i32> = v.into_par_iter().flatten_iter().collect();
+        assert_eq!(result, vec![1, 2, 3, 4, 5]);
+    }
+}
@@ -0,0 +1,334 @@
+use super::plumbing::*;
+use super::*;
+use std::cmp::min;
+
+/// `FoldChunks` is an iterator that folds over fixed-size, deterministic
+/// chunks of the base iterator, producing one folded value per chunk
+/// (the final chunk may be shorter). This struct is created by the
+/// [`fold_chunks()`] method on [`IndexedParallelIterator`].
+///
+/// [`fold_chunks()`]: trait.IndexedParallelIterator.html#method.fold_chunks
+/// [`IndexedParallelIterator`]: trait.IndexedParallelIterator.html
+#[derive(Debug, Clone)]
+pub struct FoldChunks<I, ID, F> {
+    base: I,
+    chunk_size: usize,
+    identity: ID,
+    fold_op: F,
+}
+
+pub fn new<I, ID, F>(base: I, chunk_size: usize, identity: ID, fold_op: F) -> FoldChunks<I, ID, F> {
+    FoldChunks {
+        base: base,
+        chunk_size: chunk_size,
+        identity: identity,
+        fold_op: fold_op,
+    }
+}
+
+impl<I, ID, F, U> ParallelIterator for FoldChunks<I, ID, F>
+    where I: IndexedParallelIterator,
+          ID: Fn() -> U + Sync + Send,
+          F: Fn(U, I::Item) -> U + Sync + Send,
+          U: Send
+{
+    type Item = U;
+
+    fn drive_unindexed<C>(self, consumer: C) -> C::Result
+        where C: UnindexedConsumer<Self::Item>
+    {
+        bridge(self, consumer)
+    }
+
+    fn opt_len(&self) -> Option<usize> {
+        Some(self.len())
+    }
+
+    fn len_hint(&self) -> (usize, Option<usize>) {
+        (self.len(), Some(self.len()))
+    }
+}
+
+impl<I, ID, F, U> IndexedParallelIterator for FoldChunks<I, ID, F>
+    where I: IndexedParallelIterator,
+          ID: Fn() -> U + Sync + Send,
+          F: Fn(U, I::Item) -> U + Sync + Send,
+          U: Send
+{
+    fn len(&self) -> usize {
+        (self.base.len() + self.chunk_size - 1) / self.chunk_size
+    }
+
+    fn drive<C: Consumer<Self::Item>>(self, consumer: C) -> C::Result {
+        bridge(self, consumer)
+    }
+
+    fn with_producer<CB: ProducerCallback<Self::Item>>(self, callback: CB) -> CB::Output {
+        let FoldChunks { base, chunk_size, identity, fold_op } = self;
+        let len = base.len();
+        base.with_producer(Callback {
+            len: len,
+            chunk_size: chunk_size,
+            identity: &identity,
+            fold_op: &fold_op,
+            callback: callback,
+        })
+    }
+}
+
+/// `FoldChunksWith` is like [`FoldChunks`], but seeded with a cloned `init`
+/// value for each chunk rather than an `identity` closure, exactly like the
+/// relationship between [`fold_with()`] and [`fold()`]. This struct is
+/// created by the [`fold_chunks_with()`] method on [`IndexedParallelIterator`].
+///
+/// [`FoldChunks`]: struct.FoldChunks.html
+/// [`fold_with()`]: trait.ParallelIterator.html#method.fold_with
+/// [`fold()`]: trait.ParallelIterator.html#method.fold
+/// [`fold_chunks_with()`]: trait.IndexedParallelIterator.html#method.fold_chunks_with
+/// [`IndexedParallelIterator`]: trait.IndexedParallelIterator.html
+#[derive(Debug, Clone)]
+pub struct FoldChunksWith<I, U, F> {
+    base: I,
+    chunk_size: usize,
+    item: U,
+    fold_op: F,
+}
+
+pub fn new_with<I, U, F>(base: I, chunk_size: usize, item: U, fold_op: F) -> FoldChunksWith<I, U, F> {
+    FoldChunksWith {
+        base: base,
+        chunk_size: chunk_size,
+        item: item,
+        fold_op: fold_op,
+    }
+}
+
+impl<I, U, F> ParallelIterator for FoldChunksWith<I, U, F>
+    where I: IndexedParallelIterator,
+          U: Send + Clone,
+          F: Fn(U, I::Item) -> U + Sync + Send
+{
+    type Item = U;
+
+    fn drive_unindexed<C>(self, consumer: C) -> C::Result
+        where C: UnindexedConsumer<Self::Item>
+    {
+        bridge(self, consumer)
+    }
+
+    fn opt_len(&self) -> Option<usize> {
+        Some(self.len())
+    }
+
+    fn len_hint(&self) -> (usize, Option<usize>) {
+        (self.len(), Some(self.len()))
+    }
+}
+
+impl<I, U, F> IndexedParallelIterator for FoldChunksWith<I, U, F>
+    where I: IndexedParallelIterator,
+          U: Send + Clone,
+          F: Fn(U, I::Item) -> U + Sync + Send
+{
+    fn len(&self) -> usize {
+        (self.base.len() + self.chunk_size - 1) / self.chunk_size
+    }
+
+    fn drive<C: Consumer<Self::Item>>(self, consumer: C) -> C::Result {
+        bridge(self, consumer)
+    }
+
+    fn with_producer<CB: ProducerCallback<Self::Item>>(self, callback: CB) -> CB::Output {
+        let FoldChunksWith { base, chunk_size, item, fold_op } = self;
+        let len = base.len();
+        let identity = move || item.clone();
+        base.with_producer(Callback {
+            len: len,
+            chunk_size: chunk_size,
+            identity: &identity,
+            fold_op: &fold_op,
+            callback: callback,
+        })
+    }
+}
+
+struct Callback<'f, CB, ID: 'f, F: 'f> {
+    len: usize,
+    chunk_size: usize,
+    identity: &'f ID,
+    fold_op: &'f F,
+    callback: CB,
+}
+
+impl<'f, T, U, ID, F, CB> ProducerCallback<T> for Callback<'f, CB, ID, F>
+    where CB: ProducerCallback<U>,
+          ID: Fn() -> U + Sync,
+          F: Fn(U, T) -> U + Sync
+{
+    type Output = CB::Output;
+
+    fn callback<P>(self, base: P) -> CB::Output
+        where P: Producer<Item = T>
+    {
+        let producer = FoldChunksProducer {
+            base: base,
+            len: self.len,
+            chunk_size: self.chunk_size,
+            identity: self.identity,
+            fold_op: self.fold_op,
+        };
+        self.callback.callback(producer)
+    }
+}
+
+struct FoldChunksProducer<'f, P, ID: 'f, F: 'f> {
+    base: P,
+    len: usize,
+    chunk_size: usize,
+    identity: &'f ID,
+    fold_op: &'f F,
+}
+
+impl<'f, P, ID, F, U> Producer for FoldChunksProducer<'f, P, ID, F>
+    where P: Producer,
+          ID: Fn() -> U + Sync,
+          F: Fn(U, P::Item) -> U + Sync
+{
+    type Item = U;
+    type IntoIter = FoldChunksIter<'f, P::IntoIter, ID, F>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        FoldChunksIter {
+            iter: self.base.into_iter(),
+            len: self.len,
+            chunk_size: self.chunk_size,
+            identity: self.identity,
+            fold_op: self.fold_op,
+        }
+    }
+
+    fn split_at(self, index: usize) -> (Self, Self) {
+        let elem_index = min(index * self.chunk_size, self.len);
+        let (left, right) = self.base.split_at(elem_index);
+        (FoldChunksProducer {
+             base: left,
+             len: elem_index,
+             chunk_size: self.chunk_size,
+             identity: self.identity,
+             fold_op: self.fold_op,
+         },
+         FoldChunksProducer {
+             base: right,
+             len: self.len - elem_index,
+             chunk_size: self.chunk_size,
+             identity: self.identity,
+             fold_op: self.fold_op,
+         })
+    }
+}
+
+struct FoldChunksIter<'f, I, ID: 'f, F: 'f> {
+    iter: I,
+    len: usize,
+    chunk_size: usize,
+    identity: &'f ID,
+    fold_op: &'f F,
+}
+
+impl<'f, I, ID, F, U> Iterator for FoldChunksIter<'f, I, ID, F>
+    where I: Iterator,
+          ID: Fn() -> U,
+          F: Fn(U, I::Item) -> U
+{
+    type Item = U;
+
+    fn next(&mut self) -> Option<U> {
+        if self.len == 0 {
+            return None;
+        }
+        let take = min(self.chunk_size, self.len);
+        self.len -= take;
+        let mut acc = (self.identity)();
+        for _ in 0..take {
+            let item = self.iter.next().expect("chunk shorter than its producer's length");
+            acc = (self.fold_op)(acc, item);
+        }
+        Some(acc)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let n = (self.len + self.chunk_size - 1) / self.chunk_size;
+        (n, Some(n))
+    }
+}
+
+impl<'f, I, ID, F, U> ExactSizeIterator for FoldChunksIter<'f, I, ID, F>
+    where I: Iterator,
+          ID: Fn() -> U,
+          F: Fn(U, I::Item) -> U
+{
+}
+
+impl<'f, I, ID, F, U> DoubleEndedIterator for FoldChunksIter<'f, I, ID, F>
+    where I: DoubleEndedIterator + ExactSizeIterator,
+          ID: Fn() -> U,
+          F: Fn(U, I::Item) -> U
+{
+    fn next_back(&mut self) -> Option<U> {
+        if self.len == 0 {
+            return None;
+        }
+        let rem = self.len % self.chunk_size;
+        let take = if rem == 0 { self.chunk_size } else { rem };
+        self.len -= take;
+
+        let mut buf = Vec::with_capacity(take);
+        for _ in 0..take {
+            buf.push(self.iter.next_back().expect("chunk shorter than its producer's length"));
+        }
+        buf.reverse();
+
+        let mut acc = (self.identity)();
+        for item in buf {
+            acc = (self.fold_op)(acc, item);
+        }
+        Some(acc)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn id() -> i32 {
+        0
+    }
+    fn sum(a: i32, b: i32) -> i32 {
+        a + b
+    }
+
+    #[test]
+    fn check_fold_chunks_empty() {
+        let v: Vec<i32> = vec![];
+        let result: Vec<i32> = v.into_par_iter().fold_chunks(3, id, sum).collect();
+        assert_eq!(result, Vec::<i32>::new());
+    }
+
+    #[test]
+    fn check_fold_chunks_chunk_larger_than_len() {
+        let result: Vec<i32> = vec![1, 2, 3].into_par_iter().fold_chunks(10, id, sum).collect();
+        assert_eq!(result, vec![1 + 2 + 3]);
+    }
+
+    #[test]
+    fn check_fold_chunks_rev() {
+        let result: Vec<i32> = (1..10).into_par_iter().fold_chunks(4, id, sum).rev().collect();
+        assert_eq!(result, vec![9, 5 + 6 + 7 + 8, 1 + 2 + 3 + 4]);
+    }
+
+    #[test]
+    fn check_fold_chunks_rev_chunk_larger_than_len() {
+        let result: Vec<i32> = vec![1, 2, 3].into_par_iter().fold_chunks(10, id, sum).rev().collect();
+        assert_eq!(result, vec![1 + 2 + 3]);
+    }
+}
@@ -0,0 +1,92 @@
+//! `FromParallelIterator` impls for the standard collections, built on
+//! `collect::unindexed()`: drive the iterator down into a list of per-job
+//! buffers, then flatten that list into the final container in one
+//! sequential pass, sized exactly from the buffers actually produced.
+//! `unindexed()` itself prefers the iterator's exact `opt_len()` over the
+//! advisory `len_hint()` lower bound when sizing those per-job buffers,
+//! so indexed sources avoid reallocating along the way too.
+
+use super::collect::unindexed;
+use super::*;
+use std::collections::{HashMap, LinkedList};
+use std::hash::{BuildHasher, Hash};
+
+impl<T: Send> FromParallelIterator<T> for Vec<T> {
+    fn from_par_iter<I>(par_iter: I) -> Self
+        where I: IntoParallelIterator<Item = T>
+    {
+        flatten_to_vec(unindexed(par_iter.into_par_iter()))
+    }
+}
+
+impl FromParallelIterator<char> for String {
+    fn from_par_iter<I>(par_iter: I) -> Self
+        where I: IntoParallelIterator<Item = char>
+    {
+        flatten_to_vec(unindexed(par_iter.into_par_iter()))
+            .into_iter()
+            .collect()
+    }
+}
+
+impl<K, V, S> FromParallelIterator<(K, V)> for HashMap<K, V, S>
+    where K: Eq + Hash + Send,
+          V: Send,
+          S: BuildHasher + Default + Send
+{
+    fn from_par_iter<I>(par_iter: I) -> Self
+        where I: IntoParallelIterator<Item = (K, V)>
+    {
+        let list = unindexed(par_iter.into_par_iter());
+        let len = list.iter().map(Vec::len).sum();
+        let mut map = HashMap::with_capacity_and_hasher(len, S::default());
+        for chunk in list {
+            map.extend(chunk);
+        }
+        map
+    }
+}
+
+fn flatten_to_vec<T>(list: LinkedList<Vec<T>>) -> Vec<T> {
+    let len = list.iter().map(Vec::len).sum();
+    let mut vec = Vec::with_capacity(len);
+    for chunk in list {
+        vec.extend(chunk);
+    }
+    vec
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn check_from_par_iter_vec_with_underestimated_hint() {
+        // `filter` reports the default `len_hint()` lower bound of `0`,
+        // so collecting through it exercises the "hint under-counts" path.
+        let result: Vec<i32> = (0..10).into_par_iter().filter(|&x| x % 2 == 0).collect();
+        assert_eq!(result, vec![0, 2, 4, 6, 8]);
+    }
+
+    #[test]
+    fn check_from_par_iter_vec_with_exact_opt_len() {
+        // `step_by` overrides `opt_len()` to its exact output count, so
+        // `unindexed()`'s per-job buffers should be sized exactly rather
+        // than guessed from `len_hint()`.
+        let result: Vec<i32> = (0..10).into_par_iter().step_by(3).collect();
+        assert_eq!(result, vec![0, 3, 6, 9]);
+    }
+
+    #[test]
+    fn check_from_par_iter_string() {
+        let result: String = vec!['a', 'b', 'c'].into_par_iter().collect();
+        assert_eq!(result, "abc");
+    }
+
+    #[test]
+    fn check_from_par_iter_hash_map() {
+        let map: HashMap<i32, i32> = vec![(1, 10), (2, 20)].into_par_iter().collect();
+        assert_eq!(map.len(), 2);
+        assert_eq!(map.get(&1), Some(&10));
+    }
+}
@@ -69,8 +69,11 @@
 
 pub use either::Either;
 use std::cmp::{self, Ordering};
+use std::future::Future;
 use std::iter::{Sum, Product};
 use std::ops::Fn;
+use std::pin::Pin;
+use self::executor::Executor;
 use self::plumbing::*;
 
 // There is a method to the madness here:
@@ -86,6 +89,8 @@ use self::plumbing::*;
 
 mod find;
 mod find_first_last;
+mod fold_chunks;
+pub use self::fold_chunks::{FoldChunks, FoldChunksWith};
 mod chain;
 pub use self::chain::Chain;
 mod chunks;
@@ -99,18 +104,33 @@ mod filter_map;
 pub use self::filter_map::FilterMap;
 mod flat_map;
 pub use self::flat_map::FlatMap;
+mod flat_map_iter;
+pub use self::flat_map_iter::FlatMapIter;
 mod flatten;
 pub use self::flatten::Flatten;
+mod flatten_iter;
+pub use self::flatten_iter::FlattenIter;
 mod from_par_iter;
 pub mod plumbing;
+pub mod executor;
+mod private;
+pub use self::private::Try;
 mod for_each;
+mod try_for_each;
 mod fold;
 pub use self::fold::{Fold, FoldWith};
+mod try_fold;
+pub use self::try_fold::{TryFold, TryFoldWith};
 mod reduce;
+mod try_reduce;
+mod scan;
+pub use self::scan::Scan;
 mod skip;
 pub use self::skip::Skip;
 mod splitter;
 pub use self::splitter::{split, Split};
+mod step_by;
+pub use self::step_by::StepBy;
 mod take;
 pub use self::take::Take;
 mod map;
@@ -139,6 +159,8 @@ mod sum;
 mod product;
 mod cloned;
 pub use self::cloned::Cloned;
+mod copied;
+pub use self::copied::Copied;
 mod inspect;
 pub use self::inspect::Inspect;
 mod while_some;
@@ -361,6 +383,233 @@ pub trait ParallelIterator: Sized + Send {
         self.map_with(init, op).for_each(|()| ())
     }
 
+    /// Executes `op` on each item produced by the iterator, in parallel,
+    /// short-circuiting as soon as any invocation returns `Err` (or `None`).
+    /// Outstanding jobs are cancelled, and the short-circuiting value is
+    /// returned; if no invocation fails, `Ok(())` (or `Some(())`) is returned
+    /// once every item has been visited.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rayon::prelude::*;
+    /// use std::io::{self, Write};
+    ///
+    /// let result: io::Result<()> = (0..5).into_par_iter()
+    ///     .try_for_each(|x| writeln!(io::sink(), "{:?}", x));
+    ///
+    /// assert!(result.is_ok());
+    /// ```
+    fn try_for_each<F, R>(self, op: F) -> R
+        where F: Fn(Self::Item) -> R + Sync + Send,
+              R: Try<Ok = ()> + Send
+    {
+        try_for_each::try_for_each(self, op)
+    }
+
+    /// Executes `op` on the given `init` value with each item produced by
+    /// the iterator, in parallel, short-circuiting as soon as any invocation
+    /// returns `Err` (or `None`), just like [`try_for_each()`].
+    ///
+    /// The `init` value is cloned as needed, exactly like [`for_each_with()`].
+    ///
+    /// [`try_for_each()`]: #method.try_for_each
+    /// [`for_each_with()`]: #method.for_each_with
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::sync::mpsc::channel;
+    /// use rayon::prelude::*;
+    ///
+    /// let (sender, receiver) = channel();
+    ///
+    /// let result: Result<(), &'static str> = (0..5).into_par_iter()
+    ///     .try_for_each_with(sender, |s, x| {
+    ///         s.send(x).unwrap();
+    ///         Ok(())
+    ///     });
+    ///
+    /// let mut res: Vec<_> = receiver.iter().collect();
+    /// res.sort();
+    ///
+    /// assert!(result.is_ok());
+    /// assert_eq!(&res[..], &[0, 1, 2, 3, 4]);
+    /// ```
+    fn try_for_each_with<F, T, R>(self, init: T, op: F) -> R
+        where F: Fn(&mut T, Self::Item) -> R + Sync + Send,
+              T: Send + Clone,
+              R: Try<Ok = ()> + Send
+    {
+        try_for_each::try_for_each_with(self, init, op)
+    }
+
+    /// Like [`for_each()`], but instead of blocking the calling thread until
+    /// every item has been visited, hands the work off to the rayon thread
+    /// pool and returns a `Future` that resolves once `for_each` completes.
+    ///
+    /// This is one of the `*_async` terminal methods (with
+    /// [`reduce_async()`] and [`collect_async()`]) built on the
+    /// [`executor`] module; see its docs for the scope of that abstraction.
+    ///
+    /// [`for_each()`]: #method.for_each
+    /// [`reduce_async()`]: #method.reduce_async
+    /// [`collect_async()`]: #method.collect_async
+    /// [`executor`]: executor/index.html
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rayon::prelude::*;
+    /// use std::future::Future;
+    /// use std::pin::Pin;
+    /// use std::sync::atomic::{AtomicUsize, Ordering};
+    /// use std::sync::Arc;
+    /// use std::task::{Context, Poll, RawWaker, RawWakerVTable, Waker};
+    ///
+    /// // A no-op waker: `block_on` below re-polls in a loop instead of
+    /// // waiting to be woken, so waking never needs to do anything.
+    /// fn noop_waker() -> Waker {
+    ///     fn clone(_: *const ()) -> RawWaker {
+    ///         RawWaker::new(std::ptr::null(), &VTABLE)
+    ///     }
+    ///     fn noop(_: *const ()) {}
+    ///     static VTABLE: RawWakerVTable = RawWakerVTable::new(clone, noop, noop, noop);
+    ///     unsafe { Waker::from_raw(RawWaker::new(std::ptr::null(), &VTABLE)) }
+    /// }
+    ///
+    /// // Whatever async runtime the caller is already using would drive
+    /// // this `Future`; here we just spin-poll it to completion.
+    /// fn block_on<T>(mut fut: Pin<Box<dyn Future<Output = T> + Send>>) -> T {
+    ///     let waker = noop_waker();
+    ///     let mut cx = Context::from_waker(&waker);
+    ///     loop {
+    ///         if let Poll::Ready(value) = fut.as_mut().poll(&mut cx) {
+    ///             return value;
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let visited = Arc::new(AtomicUsize::new(0));
+    /// let visited_in_op = visited.clone();
+    ///
+    /// // Hand the work off to the thread pool; `fut` resolves once every
+    /// // item has been visited.
+    /// let fut = (0..100).into_par_iter()
+    ///     .for_each_async(move |_| { visited_in_op.fetch_add(1, Ordering::SeqCst); });
+    ///
+    /// block_on(fut);
+    ///
+    /// assert_eq!(visited.load(Ordering::SeqCst), 100);
+    /// ```
+    fn for_each_async<OP>(self, op: OP) -> Pin<Box<dyn Future<Output = ()> + Send>>
+        where Self: 'static,
+              OP: Fn(Self::Item) + Sync + Send + 'static
+    {
+        executor::AsyncExecutor.execute(move || self.for_each(op))
+    }
+
+    /// Like [`reduce()`], but instead of blocking the calling thread until
+    /// the reduction completes, hands the work off to the rayon thread
+    /// pool and returns a `Future` that resolves to the final value. See
+    /// [`for_each_async()`] and the [`executor`] module docs for the
+    /// current scope of this `*_async` family.
+    ///
+    /// [`reduce()`]: #method.reduce
+    /// [`for_each_async()`]: #method.for_each_async
+    /// [`executor`]: executor/index.html
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rayon::prelude::*;
+    /// use std::future::Future;
+    /// use std::pin::Pin;
+    /// use std::task::{Context, Poll, RawWaker, RawWakerVTable, Waker};
+    ///
+    /// fn noop_waker() -> Waker {
+    ///     fn clone(_: *const ()) -> RawWaker {
+    ///         RawWaker::new(std::ptr::null(), &VTABLE)
+    ///     }
+    ///     fn noop(_: *const ()) {}
+    ///     static VTABLE: RawWakerVTable = RawWakerVTable::new(clone, noop, noop, noop);
+    ///     unsafe { Waker::from_raw(RawWaker::new(std::ptr::null(), &VTABLE)) }
+    /// }
+    ///
+    /// fn block_on<T>(mut fut: Pin<Box<dyn Future<Output = T> + Send>>) -> T {
+    ///     let waker = noop_waker();
+    ///     let mut cx = Context::from_waker(&waker);
+    ///     loop {
+    ///         if let Poll::Ready(value) = fut.as_mut().poll(&mut cx) {
+    ///             return value;
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let fut = (1..=5).into_par_iter().reduce_async(|| 0, |a, b| a + b);
+    ///
+    /// assert_eq!(block_on(fut), 1 + 2 + 3 + 4 + 5);
+    /// ```
+    fn reduce_async<OP, ID>(self, identity: ID, op: OP) -> Pin<Box<dyn Future<Output = Self::Item> + Send>>
+        where Self: 'static,
+              Self::Item: 'static,
+              OP: Fn(Self::Item, Self::Item) -> Self::Item + Sync + Send + 'static,
+              ID: Fn() -> Self::Item + Sync + Send + 'static
+    {
+        executor::AsyncExecutor.execute(move || self.reduce(identity, op))
+    }
+
+    /// Like [`collect()`], but instead of blocking the calling thread
+    /// until the collection completes, hands the work off to the rayon
+    /// thread pool and returns a `Future` that resolves to the final
+    /// container. Because this is a plain default method on
+    /// `ParallelIterator`, it composes with every combinator exactly like
+    /// `collect()` does -- e.g. `.step_by(2).collect_async()`.
+    /// See [`for_each_async()`] and the [`executor`] module docs for the
+    /// current scope of this `*_async` family.
+    ///
+    /// [`collect()`]: #method.collect
+    /// [`for_each_async()`]: #method.for_each_async
+    /// [`executor`]: executor/index.html
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rayon::prelude::*;
+    /// use std::future::Future;
+    /// use std::pin::Pin;
+    /// use std::task::{Context, Poll, RawWaker, RawWakerVTable, Waker};
+    ///
+    /// fn noop_waker() -> Waker {
+    ///     fn clone(_: *const ()) -> RawWaker {
+    ///         RawWaker::new(std::ptr::null(), &VTABLE)
+    ///     }
+    ///     fn noop(_: *const ()) {}
+    ///     static VTABLE: RawWakerVTable = RawWakerVTable::new(clone, noop, noop, noop);
+    ///     unsafe { Waker::from_raw(RawWaker::new(std::ptr::null(), &VTABLE)) }
+    /// }
+    ///
+    /// fn block_on<T>(mut fut: Pin<Box<dyn Future<Output = T> + Send>>) -> T {
+    ///     let waker = noop_waker();
+    ///     let mut cx = Context::from_waker(&waker);
+    ///     loop {
+    ///         if let Poll::Ready(value) = fut.as_mut().poll(&mut cx) {
+    ///             return value;
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let fut = (0..10).into_par_iter().step_by(3).collect_async();
+    ///
+    /// assert_eq!(block_on(fut), vec![0, 3, 6, 9]);
+    /// ```
+    fn collect_async<C>(self) -> Pin<Box<dyn Future<Output = C> + Send>>
+        where Self: 'static,
+              C: FromParallelIterator<Self::Item> + Send + 'static
+    {
+        executor::AsyncExecutor.execute(move || self.collect())
+    }
+
     /// Counts the number of items in this parallel iterator.
     ///
     /// # Examples
@@ -459,6 +708,34 @@ pub trait ParallelIterator: Sized + Send {
         cloned::new(self)
     }
 
+    /// Creates an iterator which copies all of its elements.  This may be
+    /// useful when you have an iterator over `&T`, but you need `T`, and
+    /// `T` is `Copy`. Unlike `cloned()`, the `Copy` bound here documents
+    /// that the copy is expected to be cheap and guards against an
+    /// accidental expensive clone if `T`'s definition later changes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rayon::prelude::*;
+    ///
+    /// let a = [1, 2, 3];
+    ///
+    /// let v_copied: Vec<_> = a.par_iter().copied().collect();
+    ///
+    /// // copied is the same as .map(|&x| x), for integers
+    /// let v_map: Vec<_> = a.par_iter().map(|&x| x).collect();
+    ///
+    /// assert_eq!(v_copied, vec![1, 2, 3]);
+    /// assert_eq!(v_map, vec![1, 2, 3]);
+    /// ```
+    fn copied<'a, T>(self) -> Copied<Self>
+        where T: 'a + Copy + Send,
+              Self: ParallelIterator<Item = &'a T>
+    {
+        copied::new(self)
+    }
+
     /// Applies `inspect_op` to a reference to each item of this iterator,
     /// producing a new iterator passing through the original items.  This is
     /// often useful for debugging to see what's happening in iterator stages.
@@ -581,6 +858,35 @@ pub trait ParallelIterator: Sized + Send {
         flat_map::new(self, map_op)
     }
 
+    /// Applies `map_op` to each item of this iterator to get nested plain
+    /// (sequential) iterators, producing a new iterator that flattens these
+    /// back into one. Unlike `flat_map`, the inner iterator returned by
+    /// `map_op` need not implement `IntoParallelIterator` (or be `Send`) --
+    /// it is drained sequentially inside whichever job is handling the
+    /// outer item, which is the right tradeoff when each inner sequence is
+    /// short and cheap (e.g. splitting a line into tokens).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rayon::prelude::*;
+    ///
+    /// let a = ["1 2 3", "4 5 6", "7 8 9"];
+    ///
+    /// let par_iter = a.par_iter().flat_map_iter(|s| s.split(' '));
+    ///
+    /// let vec: Vec<_> = par_iter.collect();
+    ///
+    /// assert_eq!(vec, ["1", "2", "3", "4", "5", "6", "7", "8", "9"]);
+    /// ```
+    fn flat_map_iter<F, SI>(self, map_op: F) -> FlatMapIter<Self, F>
+        where F: Fn(Self::Item) -> SI + Sync + Send,
+              SI: IntoIterator,
+              SI::Item: Send
+    {
+        flat_map_iter::new(self, map_op)
+    }
+
     /// An adaptor that flattens iterable `Item`s into one large iterator
     ///
     /// # Examples
@@ -599,6 +905,27 @@ pub trait ParallelIterator: Sized + Send {
         flatten::new(self)
     }
 
+    /// An adaptor that flattens plain, sequential iterable `Item`s into one
+    /// large parallel iterator, like `flatten()` but keeping each inner
+    /// sequence's iteration sequential, exactly like the relationship
+    /// between `flat_map_iter()` and `flat_map()`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rayon::prelude::*;
+    ///
+    /// let x: Vec<Vec<_>> = vec![vec![1, 2], vec![3, 4]];
+    /// let y: Vec<_> = x.into_par_iter().flatten_iter().collect();
+    ///
+    /// assert_eq!(y, vec![1, 2, 3, 4]);
+    /// ```
+    fn flatten_iter(self) -> FlattenIter<Self>
+        where Self::Item: IntoIterator
+    {
+        flatten_iter::new(self)
+    }
+
     /// Reduces the items in the iterator into one item using `op`.
     /// The argument `identity` should be a closure that can produce
     /// "identity" value which may be inserted into the sequence as
@@ -676,6 +1003,69 @@ pub trait ParallelIterator: Sized + Send {
             })
     }
 
+    /// Reduces the items in the iterator into one item, short-circuiting as
+    /// soon as `op` returns `Err` (or `None`). Items are combined much like
+    /// `reduce()`, except that `op` returns a `Try` value (`Result<T, E>` or
+    /// `Option<T>`) rather than `T` directly, and the first failure observed
+    /// (in an unspecified order, since `op` should be [associative]) is
+    /// returned without waiting for the rest of the iterator.
+    ///
+    /// **Note:** as with `reduce()`, the order in which `op` is applied to
+    /// combine items is not fully specified, so `op` should be associative
+    /// or the *value* of the short-circuiting error may be non-deterministic
+    /// (though short-circuiting itself always happens as soon as any failure
+    /// is observed).
+    ///
+    /// [associative]: https://en.wikipedia.org/wiki/Associative_property
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rayon::prelude::*;
+    ///
+    /// let r: Result<i32, &'static str> = [1, 2, 3].par_iter()
+    ///     .map(|&x| if x < 0 { Err("negative") } else { Ok(x) })
+    ///     .try_reduce(|| 0, |a, b| Ok(a + b));
+    /// assert_eq!(r, Ok(6));
+    /// ```
+    fn try_reduce<T, OP, ID>(self, identity: ID, op: OP) -> Self::Item
+        where OP: Fn(T, T) -> Self::Item + Sync + Send,
+              ID: Fn() -> T + Sync + Send,
+              Self::Item: Try<Ok = T> + Send
+    {
+        try_reduce::try_reduce(self, identity, op)
+    }
+
+    /// Like `try_reduce()`, but without a base case -- the first item of the
+    /// iterator (in an unspecified order) seeds the accumulator instead of
+    /// an `identity` closure.
+    ///
+    /// Returns `None` if the iterator is empty; otherwise returns `Some` of
+    /// either the combined value or the short-circuiting failure, matching
+    /// the relationship between `reduce_with()` and `reduce()`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rayon::prelude::*;
+    ///
+    /// let r: Option<Result<i32, &'static str>> = [1, 2, 3].par_iter()
+    ///     .map(|&x| if x < 0 { Err("negative") } else { Ok(x) })
+    ///     .try_reduce_with(|a, b| Ok(a + b));
+    /// assert_eq!(r, Some(Ok(6)));
+    ///
+    /// let empty: Option<Result<i32, &'static str>> = (0..0).into_par_iter()
+    ///     .map(|x| Ok(x))
+    ///     .try_reduce_with(|a, b| Ok(a + b));
+    /// assert_eq!(empty, None);
+    /// ```
+    fn try_reduce_with<T, OP>(self, op: OP) -> Option<Self::Item>
+        where OP: Fn(T, T) -> Self::Item + Sync + Send,
+              Self::Item: Try<Ok = T> + Send
+    {
+        try_reduce::try_reduce_with(self, op)
+    }
+
     /// Parallel fold is similar to sequential fold except that the
     /// sequence of items may be subdivided before it is
     /// folded. Consider a list of numbers like `22 3 77 89 46`. If
@@ -843,6 +1233,67 @@ pub trait ParallelIterator: Sized + Send {
         fold::fold_with(self, init, fold_op)
     }
 
+    /// Performs a fold that short-circuits as soon as `fold_op` returns
+    /// `Err` (or `None`), like `fold()` except that `fold_op` returns a
+    /// `Try` value. Each group's folded value is one of these `Try` values,
+    /// the same as with `fold()`, but once one group fails, the other groups
+    /// (on either side of it) may stop early or never be visited at all. Cancellation is best-effort: some
+    /// outstanding work started before the failure may still complete.
+    ///
+    /// Internally this shares the same kind of early-termination signal used
+    /// by `find_first`/`find_last` -- a flag visible to every split of the
+    /// consumer -- so that one leaf observing a failure stops its siblings
+    /// from picking up further items, without needing every leaf to finish
+    /// the group it already started.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rayon::prelude::*;
+    ///
+    /// let r: Option<i32> = (0..10).into_par_iter()
+    ///     .try_fold(|| 0, |a, b| if b == 5 { None } else { Some(a + b) })
+    ///     .try_reduce(|| 0, |a, b| Some(a + b));
+    ///
+    /// assert_eq!(r, None);
+    /// ```
+    fn try_fold<T, R, ID, F>(self, identity: ID, fold_op: F) -> TryFold<Self, ID, F>
+        where F: Fn(T, Self::Item) -> R + Sync + Send,
+              ID: Fn() -> T + Sync + Send,
+              R: Try<Ok = T> + Send
+    {
+        try_fold::try_fold(self, identity, fold_op)
+    }
+
+    /// Performs a short-circuiting fold much like `try_fold()`, but seeded
+    /// with a cloned `init` value rather than an `identity` closure, exactly
+    /// like the relationship between `fold_with()` and `fold()`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rayon::prelude::*;
+    ///
+    /// let r: Option<i32> = (0..10).into_par_iter()
+    ///     .try_fold_with(0, |a, b| if b == 5 { None } else { Some(a + b) })
+    ///     .try_reduce(|| 0, |a, b| Some(a + b));
+    ///
+    /// assert_eq!(r, None);
+    ///
+    /// let r: Option<i32> = (0..5).into_par_iter()
+    ///     .try_fold_with(0, |a, b| Some(a + b))
+    ///     .try_reduce(|| 0, |a, b| Some(a + b));
+    ///
+    /// assert_eq!(r, Some(0 + 1 + 2 + 3 + 4));
+    /// ```
+    fn try_fold_with<F, T, R>(self, init: T, fold_op: F) -> TryFoldWith<Self, T, F>
+        where F: Fn(T, Self::Item) -> R + Sync + Send,
+              T: Send + Clone,
+              R: Try<Ok = T> + Send
+    {
+        try_fold::try_fold_with(self, init, fold_op)
+    }
+
     /// Sums up the items in the iterator.
     ///
     /// Note that the order in items will be reduced is not specified,
@@ -1421,6 +1872,24 @@ pub trait ParallelIterator: Sized + Send {
     fn opt_len(&self) -> Option<usize> {
         None
     }
+
+    /// Internal method used to define the behavior of this parallel
+    /// iterator. You should not need to call this directly.
+    ///
+    /// Returns a `(lower, upper)` bound on the number of items this
+    /// iterator will produce, analogous to `Iterator::size_hint`. The
+    /// lower bound defaults to `0` and the upper bound defaults to
+    /// whatever `opt_len()` reports, so unindexed adaptors get a sane
+    /// hint for free just by overriding `opt_len()` where they can.
+    ///
+    /// Consumers may use the lower bound to pre-reserve capacity (e.g.
+    /// `Vec::with_capacity`) before folding, but the hint is advisory
+    /// only: producing more or fewer items than hinted must never panic
+    /// or change the result, only the number of reallocations along the
+    /// way.
+    fn len_hint(&self) -> (usize, Option<usize>) {
+        (0, self.opt_len())
+    }
 }
 
 impl<T: ParallelIterator> IntoParallelIterator for T {
@@ -1562,6 +2031,109 @@ pub trait IndexedParallelIterator: ParallelIterator {
         chunks::new(self, chunk_size)
     }
 
+    /// Folds fixed-size chunks of this iterator, producing one folded value
+    /// per chunk of exactly `chunk_size` items (the final chunk may be
+    /// shorter). Unlike `fold()`, where the grouping is nondeterministic and
+    /// depends on scheduling, `fold_chunks` guarantees every invocation of
+    /// `fold_op` starts a fresh accumulator after exactly `chunk_size` items
+    /// -- useful for batched I/O, fixed-size SIMD accumulation, or writing
+    /// records in blocks. This folds in place, without materializing a
+    /// `Vec` per chunk the way `.chunks(n).map(seq_fold)` would.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rayon::prelude::*;
+    ///
+    /// let nums = vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10];
+    /// let sums: Vec<i32> = nums.into_par_iter()
+    ///     .fold_chunks(3, || 0, |acc, n| acc + n)
+    ///     .collect();
+    ///
+    /// assert_eq!(sums, vec![1 + 2 + 3, 4 + 5 + 6, 7 + 8 + 9, 10]);
+    /// ```
+    fn fold_chunks<T, ID, F>(self, chunk_size: usize, identity: ID, fold_op: F) -> FoldChunks<Self, ID, F>
+        where ID: Fn() -> T + Sync + Send,
+              F: Fn(T, Self::Item) -> T + Sync + Send,
+              T: Send
+    {
+        assert!(chunk_size != 0, "chunk_size must not be zero");
+        fold_chunks::new(self, chunk_size, identity, fold_op)
+    }
+
+    /// Same as `fold_chunks()`, but seeded with a cloned `init` value for
+    /// every chunk rather than an `identity` closure, exactly like the
+    /// relationship between `fold_with()` and `fold()`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rayon::prelude::*;
+    ///
+    /// let nums = vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10];
+    /// let sums: Vec<i32> = nums.into_par_iter()
+    ///     .fold_chunks_with(3, 0, |acc, n| acc + n)
+    ///     .collect();
+    ///
+    /// assert_eq!(sums, vec![1 + 2 + 3, 4 + 5 + 6, 7 + 8 + 9, 10]);
+    /// ```
+    fn fold_chunks_with<T, F>(self, chunk_size: usize, init: T, fold_op: F) -> FoldChunksWith<Self, T, F>
+        where T: Send + Clone,
+              F: Fn(T, Self::Item) -> T + Sync + Send
+    {
+        assert!(chunk_size != 0, "chunk_size must not be zero");
+        fold_chunks::new_with(self, chunk_size, init, fold_op)
+    }
+
+    /// Performs a parallel prefix scan, producing an iterator of the running
+    /// accumulations of `op` over this iterator's items, in index order. The
+    /// `i`th output is the reduction of the first `i + 1` inputs, so the last
+    /// output (if any) is equivalent to `self.reduce(identity, op)`.
+    ///
+    /// As with `reduce`, `identity()` should produce a true identity for
+    /// `op`, and `op` should be [associative] or the results will be
+    /// non-deterministic, since the exact grouping used to combine items in
+    /// parallel is not specified.
+    ///
+    /// [associative]: https://en.wikipedia.org/wiki/Associative_property
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rayon::prelude::*;
+    ///
+    /// let v: Vec<_> = (1..=5).into_par_iter().scan(|| 0, |a, b| a + b).collect();
+    /// assert_eq!(v, [1, 3, 6, 10, 15]);
+    /// ```
+    fn scan<ID, F>(self, identity: ID, op: F) -> Scan<Self, ID, F>
+        where F: Fn(Self::Item, Self::Item) -> Self::Item + Sync + Send,
+              ID: Fn() -> Self::Item + Sync + Send,
+              Self::Item: Clone + Sync
+    {
+        scan::new(self, identity, op)
+    }
+
+    /// Performs an exclusive parallel prefix scan: like `scan`, but the
+    /// `i`th output is the reduction of the first `i` inputs (so the first
+    /// output is always `identity()`, and the final input's reduction is
+    /// dropped rather than appearing as an extra output).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rayon::prelude::*;
+    ///
+    /// let v: Vec<_> = (1..=5).into_par_iter().scan_exclusive(|| 0, |a, b| a + b).collect();
+    /// assert_eq!(v, [0, 1, 3, 6, 10]);
+    /// ```
+    fn scan_exclusive<ID, F>(self, identity: ID, op: F) -> Scan<Self, ID, F>
+        where F: Fn(Self::Item, Self::Item) -> Self::Item + Sync + Send,
+              ID: Fn() -> Self::Item + Sync + Send,
+              Self::Item: Clone + Sync
+    {
+        scan::new_exclusive(self, identity, op)
+    }
+
     /// Lexicographically compares the elements of this `ParallelIterator` with those of
     /// another.
     fn cmp<I>(self, other: I) -> Ordering
@@ -1670,11 +2242,42 @@ pub trait IndexedParallelIterator: ParallelIterator {
         take::new(self, n)
     }
 
+    /// Creates an iterator that steps by the given amount, starting from
+    /// the first element, analogous to `std::iter::Iterator::step_by`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rayon::prelude::*;
+    ///
+    /// let range = (3..10);
+    /// let result: Vec<i32> = range.into_par_iter().step_by(3).collect();
+    ///
+    /// assert_eq!(result, [3, 6, 9])
+    /// ```
+    fn step_by(self, step: usize) -> StepBy<Self> {
+        assert!(step != 0, "step must not be zero");
+        step_by::new(self, step)
+    }
+
     /// Searches for **some** item in the parallel iterator that
     /// matches the given predicate, and returns its index.  Like
     /// `ParallelIterator::find_any`, the parallel search will not
     /// necessarily find the **first** match, and once a match is
     /// found we'll attempt to stop processing any more.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rayon::prelude::*;
+    ///
+    /// let a = [1, 2, 3, 3];
+    ///
+    /// let i = a.par_iter().position_any(|&x| x == 3).unwrap();
+    /// assert!(i == 2 || i == 3);
+    ///
+    /// assert_eq!(a.par_iter().position_any(|&x| x == 100), None);
+    /// ```
     fn position_any<P>(self, predicate: P) -> Option<usize>
         where P: Fn(Self::Item) -> bool + Sync + Send
     {
@@ -1696,6 +2299,18 @@ pub trait IndexedParallelIterator: ParallelIterator {
     /// sequential `HashMap` iteration, so "first" may be nebulous.  If you
     /// just want the first match that discovered anywhere in the iterator,
     /// `position_any` is a better choice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rayon::prelude::*;
+    ///
+    /// let a = [1, 2, 3, 3];
+    ///
+    /// assert_eq!(a.par_iter().position_first(|&x| x == 3), Some(2));
+    ///
+    /// assert_eq!(a.par_iter().position_first(|&x| x == 100), None);
+    /// ```
     fn position_first<P>(self, predicate: P) -> Option<usize>
         where P: Fn(Self::Item) -> bool + Sync + Send
     {
@@ -1717,6 +2332,18 @@ pub trait IndexedParallelIterator: ParallelIterator {
     /// sequential `HashMap` iteration, so "last" may be nebulous.  When the
     /// order doesn't actually matter to you, `position_any` is a better
     /// choice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rayon::prelude::*;
+    ///
+    /// let a = [1, 2, 3, 3];
+    ///
+    /// assert_eq!(a.par_iter().position_last(|&x| x == 3), Some(3));
+    ///
+    /// assert_eq!(a.par_iter().position_last(|&x| x == 100), None);
+    /// ```
     fn position_last<P>(self, predicate: P) -> Option<usize>
         where P: Fn(Self::Item) -> bool + Sync + Send
     {
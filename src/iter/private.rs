@@ -0,0 +1,61 @@
+//! The `Try` trait and supporting infrastructure for `try_fold`, `try_reduce`,
+//! and `try_for_each`.
+//!
+//! This mirrors (a stable-friendly subset of) `std::ops::Try`, which is not
+//! available on stable Rust. It is `pub` only so that it can appear in the
+//! signatures of the `try_*` methods; users should not need to implement it
+//! themselves, hence the leading underscore-free but intentionally sparse
+//! documentation.
+
+/// Allows a type to be used as the output of a closure passed to `try_fold`,
+/// `try_fold_with`, `try_reduce`, `try_reduce_with`, and `try_for_each`.
+/// Implemented for `Result<T, E>` and `Option<T>`.
+pub trait Try {
+    /// The type of a successful value.
+    type Ok;
+    /// The type of a failing value; for `Option`, this is `()`.
+    type Error;
+
+    /// Decomposes `self` into either a success or a failure.
+    fn into_result(self) -> Result<Self::Ok, Self::Error>;
+
+    /// Constructs a successful value.
+    fn from_ok(v: Self::Ok) -> Self;
+
+    /// Constructs a failing value.
+    fn from_error(v: Self::Error) -> Self;
+}
+
+impl<T, E> Try for Result<T, E> {
+    type Ok = T;
+    type Error = E;
+
+    fn into_result(self) -> Result<T, E> {
+        self
+    }
+
+    fn from_ok(v: T) -> Self {
+        Ok(v)
+    }
+
+    fn from_error(v: E) -> Self {
+        Err(v)
+    }
+}
+
+impl<T> Try for Option<T> {
+    type Ok = T;
+    type Error = ();
+
+    fn into_result(self) -> Result<T, ()> {
+        self.ok_or(())
+    }
+
+    fn from_ok(v: T) -> Self {
+        Some(v)
+    }
+
+    fn from_error(_: ()) -> Self {
+        None
+    }
+}
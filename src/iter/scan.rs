@@ -0,0 +1,259 @@
+use super::plumbing::*;
+use super::*;
+use std::cmp;
+
+/// Whether a `Scan` includes the current item in its output (`Inclusive`)
+/// or only the accumulation of the items strictly before it (`Exclusive`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ScanMode {
+    Inclusive,
+    Exclusive,
+}
+
+/// `Scan` is an iterator that computes a running (parallel prefix) reduction
+/// over its base iterator. This struct is created by the [`scan()`] and
+/// [`scan_exclusive()`] methods on [`IndexedParallelIterator`]
+///
+/// [`scan()`]: trait.IndexedParallelIterator.html#method.scan
+/// [`scan_exclusive()`]: trait.IndexedParallelIterator.html#method.scan_exclusive
+/// [`IndexedParallelIterator`]: trait.IndexedParallelIterator.html
+#[derive(Debug, Clone)]
+pub struct Scan<I, ID, F> {
+    base: I,
+    identity: ID,
+    scan_op: F,
+    mode: ScanMode,
+}
+
+pub fn new<I, ID, F>(base: I, identity: ID, scan_op: F) -> Scan<I, ID, F> {
+    Scan {
+        base: base,
+        identity: identity,
+        scan_op: scan_op,
+        mode: ScanMode::Inclusive,
+    }
+}
+
+pub fn new_exclusive<I, ID, F>(base: I, identity: ID, scan_op: F) -> Scan<I, ID, F> {
+    Scan {
+        base: base,
+        identity: identity,
+        scan_op: scan_op,
+        mode: ScanMode::Exclusive,
+    }
+}
+
+impl<I, ID, F> ParallelIterator for Scan<I, ID, F>
+    where I: IndexedParallelIterator,
+          ID: Fn() -> I::Item + Sync + Send,
+          F: Fn(I::Item, I::Item) -> I::Item + Sync + Send,
+          I::Item: Clone + Send + Sync
+{
+    type Item = I::Item;
+
+    fn drive_unindexed<C>(self, consumer: C) -> C::Result
+        where C: UnindexedConsumer<Self::Item>
+    {
+        bridge(self, consumer)
+    }
+
+    fn opt_len(&self) -> Option<usize> {
+        Some(self.len())
+    }
+
+    fn len_hint(&self) -> (usize, Option<usize>) {
+        (self.len(), Some(self.len()))
+    }
+}
+
+impl<I, ID, F> IndexedParallelIterator for Scan<I, ID, F>
+    where I: IndexedParallelIterator,
+          ID: Fn() -> I::Item + Sync + Send,
+          F: Fn(I::Item, I::Item) -> I::Item + Sync + Send,
+          I::Item: Clone + Send + Sync
+{
+    fn len(&self) -> usize {
+        self.base.len()
+    }
+
+    fn drive<C: Consumer<Self::Item>>(self, consumer: C) -> C::Result {
+        bridge(self, consumer)
+    }
+
+    fn with_producer<CB: ProducerCallback<Self::Item>>(self, callback: CB) -> CB::Output {
+        let Scan { base, identity, scan_op, mode } = self;
+
+        // Pass zero: flatten the base iterator into a contiguous buffer, in
+        // parallel, so the two scan passes below have a fixed block
+        // structure (a slice) to recurse over instead of an arbitrary
+        // producer.
+        let mut items: Vec<I::Item> = Vec::with_capacity(base.len());
+        base.collect_into_vec(&mut items);
+
+        if !items.is_empty() {
+            // Blocks are sized so that there are a handful per thread; too
+            // few and the down-sweep has no parallelism left to exploit,
+            // too many and the per-block overhead dominates.
+            let block_size = cmp::max(1, items.len() / (::rayon_core::current_num_threads() * 4));
+
+            // Up-sweep: fold each block down to a single total, in
+            // parallel, recursively halving the blocks with `join` until
+            // a leaf is small enough to total up sequentially.
+            let totals = up_sweep(&items, block_size, &identity, &scan_op);
+
+            // The exclusive prefix sum over block totals is tiny compared
+            // to `items.len()` (one entry per block), so it is folded with
+            // a plain sequential loop.
+            let mut offsets = Vec::with_capacity(totals.len());
+            let mut running = identity();
+            for total in totals {
+                offsets.push(running.clone());
+                running = scan_op(running, total);
+            }
+
+            // Down-sweep: apply each block's prefix offset and scan within
+            // the block, all blocks running in parallel.
+            down_sweep(&mut items, &offsets, block_size, mode, &scan_op);
+        }
+
+        callback.callback(ScanProducer { items: items })
+    }
+}
+
+/// Folds each `block_size`-sized run of `items` down to a single total,
+/// recursing with `join` so sibling blocks are totalled in parallel. The
+/// returned totals are in the same left-to-right order as the blocks.
+fn up_sweep<T, ID, F>(items: &[T], block_size: usize, identity: &ID, scan_op: &F) -> Vec<T>
+    where T: Clone + Send + Sync,
+          ID: Fn() -> T + Sync,
+          F: Fn(T, T) -> T + Sync
+{
+    if items.len() <= block_size {
+        let mut total = identity();
+        for item in items {
+            total = scan_op(total, item.clone());
+        }
+        return vec![total];
+    }
+
+    let mid = cmp::max(block_size, (items.len() / 2 / block_size) * block_size);
+    let (left, right) = items.split_at(mid);
+    let (mut left_totals, right_totals) =
+        ::rayon_core::join(|| up_sweep(left, block_size, identity, scan_op),
+                            || up_sweep(right, block_size, identity, scan_op));
+    left_totals.extend(right_totals);
+    left_totals
+}
+
+/// Scans each `block_size`-sized run of `items` in place, seeded with that
+/// block's precomputed `offsets` entry, recursing with `join` so sibling
+/// blocks are scanned in parallel. `offsets` must have exactly as many
+/// entries as `up_sweep` would produce for a slice of `items.len()`.
+fn down_sweep<T, F>(items: &mut [T], offsets: &[T], block_size: usize, mode: ScanMode, scan_op: &F)
+    where T: Clone + Send + Sync,
+          F: Fn(T, T) -> T + Sync
+{
+    if items.len() <= block_size {
+        let mut prefix = offsets[0].clone();
+        for item in items.iter_mut() {
+            match mode {
+                ScanMode::Inclusive => {
+                    prefix = scan_op(prefix, item.clone());
+                    *item = prefix.clone();
+                }
+                ScanMode::Exclusive => {
+                    let running = prefix.clone();
+                    prefix = scan_op(prefix, item.clone());
+                    *item = running;
+                }
+            }
+        }
+        return;
+    }
+
+    let mid = cmp::max(block_size, (items.len() / 2 / block_size) * block_size);
+    let num_left_blocks = (mid + block_size - 1) / block_size;
+    let (left_items, right_items) = items.split_at_mut(mid);
+    let (left_offsets, right_offsets) = offsets.split_at(num_left_blocks);
+    ::rayon_core::join(|| down_sweep(left_items, left_offsets, block_size, mode, scan_op),
+                        || down_sweep(right_items, right_offsets, block_size, mode, scan_op));
+}
+
+struct ScanProducer<T> {
+    items: Vec<T>,
+}
+
+impl<T: Send> Producer for ScanProducer<T> {
+    type Item = T;
+    type IntoIter = ::std::vec::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.items.into_iter()
+    }
+
+    fn split_at(self, index: usize) -> (Self, Self) {
+        let mut items = self.items;
+        let right = items.split_off(index);
+        (ScanProducer { items: items }, ScanProducer { items: right })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn check_scan_empty() {
+        let v: Vec<i32> = vec![];
+        let result: Vec<i32> = v.into_par_iter().scan(|| 0, |a, b| a + b).collect();
+        assert_eq!(result, Vec::<i32>::new());
+    }
+
+    #[test]
+    fn check_scan_exclusive_empty() {
+        let v: Vec<i32> = vec![];
+        let result: Vec<i32> = v.into_par_iter().scan_exclusive(|| 0, |a, b| a + b).collect();
+        assert_eq!(result, Vec::<i32>::new());
+    }
+
+    #[test]
+    fn check_scan_single() {
+        let result: Vec<i32> = vec![5].into_par_iter().scan(|| 0, |a, b| a + b).collect();
+        assert_eq!(result, vec![5]);
+    }
+
+    #[test]
+    fn check_scan_exclusive_single() {
+        let result: Vec<i32> = vec![5].into_par_iter().scan_exclusive(|| 0, |a, b| a + b).collect();
+        assert_eq!(result, vec![0]);
+    }
+
+    #[test]
+    fn check_up_sweep_down_sweep_uneven_block_size() {
+        // 7 items with a block_size of 3 makes blocks of [3, 3, 1], so the
+        // last block's total and offset slice are shorter than the rest --
+        // exactly the case the dynamic block-size computation in
+        // `with_producer` would rarely exercise on its own.
+        let items: Vec<i32> = (1..=7).collect();
+        let totals = up_sweep(&items, 3, &|| 0, &|a, b| a + b);
+        assert_eq!(totals, vec![1 + 2 + 3, 4 + 5 + 6, 7]);
+
+        let mut offsets = Vec::with_capacity(totals.len());
+        let mut running = 0;
+        for total in &totals {
+            offsets.push(running);
+            running += total;
+        }
+
+        let mut scanned = items.clone();
+        down_sweep(&mut scanned, &offsets, 3, ScanMode::Inclusive, &|a, b| a + b);
+        let mut acc = 0;
+        let expected: Vec<i32> = items.iter()
+            .map(|&x| {
+                acc += x;
+                acc
+            })
+            .collect();
+        assert_eq!(scanned, expected);
+    }
+}
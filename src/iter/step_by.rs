@@ -0,0 +1,156 @@
+use super::plumbing::*;
+use super::*;
+
+/// `StepBy` is an iterator that skips `step - 1` elements between each
+/// yielded element. This struct is created by the [`step_by()`] method on
+/// [`IndexedParallelIterator`].
+///
+/// [`step_by()`]: trait.IndexedParallelIterator.html#method.step_by
+/// [`IndexedParallelIterator`]: trait.IndexedParallelIterator.html
+#[derive(Debug, Clone)]
+pub struct StepBy<I> {
+    base: I,
+    step: usize,
+}
+
+pub fn new<I>(base: I, step: usize) -> StepBy<I> {
+    StepBy {
+        base: base,
+        step: step,
+    }
+}
+
+impl<I> ParallelIterator for StepBy<I>
+    where I: IndexedParallelIterator
+{
+    type Item = I::Item;
+
+    fn drive_unindexed<C>(self, consumer: C) -> C::Result
+        where C: UnindexedConsumer<Self::Item>
+    {
+        bridge(self, consumer)
+    }
+
+    fn opt_len(&self) -> Option<usize> {
+        Some(self.len())
+    }
+
+    fn len_hint(&self) -> (usize, Option<usize>) {
+        (self.len(), Some(self.len()))
+    }
+}
+
+impl<I> IndexedParallelIterator for StepBy<I>
+    where I: IndexedParallelIterator
+{
+    fn len(&self) -> usize {
+        // This is `Iterator::step_by`'s length calculation: every `step`-th
+        // index, rounded up.
+        (self.base.len() + self.step - 1) / self.step
+    }
+
+    fn drive<C: Consumer<Self::Item>>(self, consumer: C) -> C::Result {
+        bridge(self, consumer)
+    }
+
+    fn with_producer<CB: ProducerCallback<Self::Item>>(self, callback: CB) -> CB::Output {
+        // Track the *base* producer's real length, not the stepped count,
+        // since `split_at` has to clamp against how many elements are
+        // actually left in `base` -- the `Producer` contract doesn't let a
+        // producer's own element count be queried once it's been built.
+        let base_len = self.base.len();
+        let step = self.step;
+        return self.base.with_producer(Callback {
+            callback: callback,
+            base_len: base_len,
+            step: step,
+        });
+
+        struct Callback<CB> {
+            callback: CB,
+            base_len: usize,
+            step: usize,
+        }
+
+        impl<T, CB> ProducerCallback<T> for Callback<CB>
+            where CB: ProducerCallback<T>
+        {
+            type Output = CB::Output;
+
+            fn callback<P>(self, base: P) -> CB::Output
+                where P: Producer<Item = T>
+            {
+                let producer = StepByProducer {
+                    base: base,
+                    base_len: self.base_len,
+                    step: self.step,
+                };
+                self.callback.callback(producer)
+            }
+        }
+    }
+}
+
+struct StepByProducer<P> {
+    base: P,
+    base_len: usize,
+    step: usize,
+}
+
+impl<P> Producer for StepByProducer<P>
+    where P: Producer
+{
+    type Item = P::Item;
+    type IntoIter = ::std::iter::StepBy<P::IntoIter>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        // `step_by` on a regular iterator is 1-based ("take every element,
+        // then skip `step - 1`"), matching the index mapping below.
+        self.base.into_iter().step_by(self.step)
+    }
+
+    fn split_at(self, index: usize) -> (Self, Self) {
+        // Clamp against `base_len`, the real number of elements left in
+        // `base` -- `index` may legitimately equal the stepped count
+        // (e.g. when another combinator aligns a full-length split), and
+        // `index * step` overshoots `base_len` by up to `step - 1` in that
+        // case.
+        let elem_index = ::std::cmp::min(index * self.step, self.base_len);
+        let (left, right) = self.base.split_at(elem_index);
+        (StepByProducer {
+             base: left,
+             base_len: elem_index,
+             step: self.step,
+         },
+         StepByProducer {
+             base: right,
+             base_len: self.base_len - elem_index,
+             step: self.step,
+         })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn check_step_by_empty() {
+        let v: Vec<i32> = vec![];
+        let result: Vec<i32> = v.into_par_iter().step_by(2).collect();
+        assert_eq!(result, Vec::<i32>::new());
+    }
+
+    #[test]
+    #[should_panic(expected = "step must not be zero")]
+    fn check_step_by_zero_panics() {
+        let _: Vec<i32> = (0..10).into_par_iter().step_by(0).collect();
+    }
+
+    #[test]
+    fn check_step_by_uneven_len() {
+        let result: Vec<i32> = (0..10).into_par_iter().step_by(3).collect();
+        assert_eq!(result, vec![0, 3, 6, 9]);
+        assert_eq!((0..10).into_par_iter().step_by(3).len(), 4);
+    }
+}
@@ -0,0 +1,243 @@
+use super::plumbing::*;
+use super::private::Try;
+use super::ParallelIterator;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+/// `TryFold` is an iterator that applies a short-circuiting fold over
+/// groups of items of the base iterator. This struct is created by the
+/// [`try_fold()`] method on [`ParallelIterator`].
+///
+/// [`try_fold()`]: trait.ParallelIterator.html#method.try_fold
+/// [`ParallelIterator`]: trait.ParallelIterator.html
+#[derive(Clone)]
+pub struct TryFold<I, ID, F> {
+    base: I,
+    identity: ID,
+    fold_op: F,
+}
+
+pub fn try_fold<I, ID, F, T>(base: I, identity: ID, fold_op: F) -> TryFold<I, ID, F>
+    where I: ParallelIterator,
+          F: Fn(T::Ok, I::Item) -> T + Sync,
+          ID: Fn() -> T::Ok + Sync,
+          T: Try + Send
+{
+    TryFold {
+        base: base,
+        identity: identity,
+        fold_op: fold_op,
+    }
+}
+
+/// `TryFoldWith` is an iterator that applies a short-circuiting fold over
+/// groups of items of the base iterator, seeded with a cloned `init` value
+/// rather than an `identity` closure. This struct is created by the
+/// [`try_fold_with()`] method on [`ParallelIterator`].
+///
+/// [`try_fold_with()`]: trait.ParallelIterator.html#method.try_fold_with
+/// [`ParallelIterator`]: trait.ParallelIterator.html
+#[derive(Clone)]
+pub struct TryFoldWith<I, U, F> {
+    base: I,
+    item: U,
+    fold_op: F,
+}
+
+pub fn try_fold_with<I, U, F, T>(base: I, item: U, fold_op: F) -> TryFoldWith<I, U, F>
+    where I: ParallelIterator,
+          F: Fn(U, I::Item) -> T + Sync,
+          U: Clone + Send,
+          T: Try<Ok = U> + Send
+{
+    TryFoldWith {
+        base: base,
+        item: item,
+        fold_op: fold_op,
+    }
+}
+
+impl<U, I, ID, F, T> ParallelIterator for TryFold<I, ID, F>
+    where I: ParallelIterator,
+          F: Fn(U, I::Item) -> T + Sync + Send,
+          ID: Fn() -> U + Sync + Send,
+          T: Try<Ok = U> + Send,
+          U: Send
+{
+    type Item = T;
+
+    fn drive_unindexed<C>(self, consumer: C) -> C::Result
+        where C: UnindexedConsumer<Self::Item>
+    {
+        let full = Arc::new(AtomicBool::new(false));
+        let consumer1 = TryFoldConsumer {
+            base: consumer,
+            fold_op: &self.fold_op,
+            identity: &self.identity,
+            full: &full,
+        };
+        self.base.drive_unindexed(consumer1)
+    }
+}
+
+impl<U, I, F, T> ParallelIterator for TryFoldWith<I, U, F>
+    where I: ParallelIterator,
+          F: Fn(U, I::Item) -> T + Sync + Send,
+          U: Clone + Send,
+          T: Try<Ok = U> + Send
+{
+    type Item = T;
+
+    fn drive_unindexed<C>(self, consumer: C) -> C::Result
+        where C: UnindexedConsumer<Self::Item>
+    {
+        let item = self.item;
+        let identity = move || item.clone();
+        let full = Arc::new(AtomicBool::new(false));
+        let consumer1 = TryFoldConsumer {
+            base: consumer,
+            fold_op: &self.fold_op,
+            identity: &identity,
+            full: &full,
+        };
+        self.base.drive_unindexed(consumer1)
+    }
+}
+
+struct TryFoldConsumer<'c, C, ID: 'c, F: 'c> {
+    base: C,
+    fold_op: &'c F,
+    identity: &'c ID,
+    full: &'c Arc<AtomicBool>,
+}
+
+impl<'r, U, T, ID, F, C> Consumer<U> for TryFoldConsumer<'r, C, ID, F>
+    where C: Consumer<T>,
+          F: Fn(ID::Output, U) -> T + Sync,
+          ID: Fn() -> ID::Output + Sync,
+          T: Try<Ok = ID::Output> + Send,
+          ID::Output: Send
+{
+    type Folder = TryFoldFolder<'r, C::Folder, F, T>;
+    type Reducer = C::Reducer;
+    type Result = C::Result;
+
+    fn split_at(self, index: usize) -> (Self, Self, Self::Reducer) {
+        let (left, right, reducer) = self.base.split_at(index);
+        (TryFoldConsumer { base: left, ..self },
+         TryFoldConsumer { base: right, ..self },
+         reducer)
+    }
+
+    fn into_folder(self) -> Self::Folder {
+        TryFoldFolder {
+            base: self.base.into_folder(),
+            fold_op: self.fold_op,
+            acc: Acc::Continue((self.identity)()),
+            full: self.full,
+        }
+    }
+
+    fn full(&self) -> bool {
+        self.full.load(Ordering::Relaxed) || self.base.full()
+    }
+}
+
+impl<'r, U, T, ID, F, C> UnindexedConsumer<U> for TryFoldConsumer<'r, C, ID, F>
+    where C: UnindexedConsumer<T>,
+          F: Fn(ID::Output, U) -> T + Sync,
+          ID: Fn() -> ID::Output + Sync,
+          T: Try<Ok = ID::Output> + Send,
+          ID::Output: Send
+{
+    fn split_off_left(&self) -> Self {
+        TryFoldConsumer {
+            base: self.base.split_off_left(),
+            fold_op: self.fold_op,
+            identity: self.identity,
+            full: self.full,
+        }
+    }
+
+    fn to_reducer(&self) -> Self::Reducer {
+        self.base.to_reducer()
+    }
+}
+
+enum Acc<Ok, T> {
+    Continue(Ok),
+    Done(T),
+}
+
+struct TryFoldFolder<'r, C, F: 'r, T: Try> {
+    base: C,
+    fold_op: &'r F,
+    acc: Acc<T::Ok, T>,
+    full: &'r Arc<AtomicBool>,
+}
+
+impl<'r, C, F, T, U> Folder<U> for TryFoldFolder<'r, C, F, T>
+    where C: Folder<T>,
+          F: Fn(T::Ok, U) -> T + 'r,
+          T: Try
+{
+    type Result = C::Result;
+
+    fn consume(mut self, item: U) -> Self {
+        if let Acc::Continue(acc) = self.acc {
+            // `acc` here is the `T::Ok` held by `Acc::Continue`.
+            let result = (self.fold_op)(acc, item);
+            self.acc = match result.into_result() {
+                Ok(ok) => Acc::Continue(ok),
+                Err(e) => {
+                    self.full.store(true, Ordering::Relaxed);
+                    Acc::Done(T::from_error(e))
+                }
+            };
+        }
+        self
+    }
+
+    fn complete(self) -> Self::Result {
+        let value = match self.acc {
+            Acc::Continue(ok) => T::from_ok(ok),
+            Acc::Done(t) => t,
+        };
+        self.base.consume(value).complete()
+    }
+
+    fn full(&self) -> bool {
+        self.full.load(Ordering::Relaxed) || self.base.full()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn check_try_fold_ok() {
+        let result: Option<i32> = (1..6).into_par_iter()
+            .try_fold(|| 0, |a, b| Some(a + b))
+            .try_reduce(|| 0, |a, b| Some(a + b));
+        assert_eq!(result, Some(1 + 2 + 3 + 4 + 5));
+    }
+
+    #[test]
+    fn check_try_fold_short_circuits() {
+        // Once any item lands on 3, the whole computation must report
+        // failure, no matter how the items were split across jobs.
+        let result: Option<i32> = (0..100).into_par_iter()
+            .try_fold(|| 0, |a, b| if b == 3 { None } else { Some(a + b) })
+            .try_reduce(|| 0, |a, b| Some(a + b));
+        assert_eq!(result, None);
+    }
+
+    #[test]
+    fn check_try_fold_with() {
+        let result: Option<i32> = (1..6).into_par_iter()
+            .try_fold_with(0, |a, b| Some(a + b))
+            .try_reduce(|| 0, |a, b| Some(a + b));
+        assert_eq!(result, Some(1 + 2 + 3 + 4 + 5));
+    }
+}
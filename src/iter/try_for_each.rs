@@ -0,0 +1,74 @@
+use super::private::Try;
+use super::ParallelIterator;
+
+/// Executes `op` on each item, short-circuiting as soon as one returns a
+/// failure. See the [`try_for_each()`] method on [`ParallelIterator`] for
+/// details.
+///
+/// [`try_for_each()`]: trait.ParallelIterator.html#method.try_for_each
+/// [`ParallelIterator`]: trait.ParallelIterator.html
+pub fn try_for_each<PI, F, R>(pi: PI, op: F) -> R
+    where PI: ParallelIterator,
+          F: Fn(PI::Item) -> R + Sync + Send,
+          R: Try<Ok = ()> + Send
+{
+    pi.try_fold(|| (), move |(), item| op(item))
+        .reduce(|| R::from_ok(()),
+                |left, right| match left.into_result() {
+                    Ok(()) => right,
+                    Err(e) => R::from_error(e),
+                })
+}
+
+/// Like [`try_for_each()`], but each invocation of `op` is passed a mutable
+/// reference to a clone of `init`, so `init` itself need not be `Sync`.
+/// See [`for_each_with()`].
+///
+/// [`try_for_each()`]: trait.ParallelIterator.html#method.try_for_each
+/// [`for_each_with()`]: trait.ParallelIterator.html#method.for_each_with
+pub fn try_for_each_with<PI, T, F, R>(pi: PI, init: T, op: F) -> R
+    where PI: ParallelIterator,
+          F: Fn(&mut T, PI::Item) -> R + Sync + Send,
+          T: Send + Clone,
+          R: Try<Ok = ()> + Send
+{
+    pi.map_with(init, op)
+        .try_fold(|| (), |(), item: R| item)
+        .reduce(|| R::from_ok(()),
+                |left, right| match left.into_result() {
+                    Ok(()) => right,
+                    Err(e) => R::from_error(e),
+                })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    #[test]
+    fn check_try_for_each_ok() {
+        let result: Result<(), &'static str> = (0..10).into_par_iter().try_for_each(|_| Ok(()));
+        assert_eq!(result, Ok(()));
+    }
+
+    #[test]
+    fn check_try_for_each_short_circuits() {
+        let result: Result<(), &'static str> = (0..100).into_par_iter()
+            .try_for_each(|x| if x == 42 { Err("boom") } else { Ok(()) });
+        assert_eq!(result, Err("boom"));
+    }
+
+    #[test]
+    fn check_try_for_each_with_ok() {
+        let counter = Arc::new(AtomicUsize::new(0));
+        let result: Result<(), &'static str> = (0..10).into_par_iter()
+            .try_for_each_with(counter.clone(), |c, _| {
+                c.fetch_add(1, Ordering::SeqCst);
+                Ok(())
+            });
+        assert_eq!(result, Ok(()));
+        assert_eq!(counter.load(Ordering::SeqCst), 10);
+    }
+}
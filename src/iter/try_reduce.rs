@@ -0,0 +1,102 @@
+use super::private::Try;
+use super::ParallelIterator;
+
+/// Performs a short-circuiting parallel reduction over `Try`-wrapped items,
+/// stopping as soon as any item (or intermediate reduction) is a failure.
+/// See the [`try_reduce()`] method on [`ParallelIterator`] for details.
+///
+/// [`try_reduce()`]: trait.ParallelIterator.html#method.try_reduce
+/// [`ParallelIterator`]: trait.ParallelIterator.html
+pub fn try_reduce<PI, R, ID, OP>(pi: PI, identity: ID, reduce_op: OP) -> R
+    where PI: ParallelIterator<Item = R>,
+          OP: Fn(R::Ok, R::Ok) -> R + Sync + Send,
+          ID: Fn() -> R::Ok + Sync + Send,
+          R: Try + Send
+{
+    let identity = &identity;
+    let reduce_op = &reduce_op;
+    pi.try_fold(identity, move |acc, item: R| -> R {
+            match item.into_result() {
+                Ok(ok) => reduce_op(acc, ok),
+                Err(e) => R::from_error(e),
+            }
+        })
+        .reduce(move || R::from_ok(identity()),
+                move |left, right| match (left.into_result(), right.into_result()) {
+                    (Ok(a), Ok(b)) => reduce_op(a, b),
+                    (Err(e), _) | (_, Err(e)) => R::from_error(e),
+                })
+}
+
+/// Like [`try_reduce()`], but without a base case -- the first item observed
+/// (in an unspecified order) seeds the accumulator instead. Returns `None`
+/// if the iterator was empty.
+///
+/// [`try_reduce()`]: trait.ParallelIterator.html#method.try_reduce
+pub fn try_reduce_with<PI, R, OP>(pi: PI, reduce_op: OP) -> Option<R>
+    where PI: ParallelIterator<Item = R>,
+          OP: Fn(R::Ok, R::Ok) -> R + Sync + Send,
+          R: Try + Send
+{
+    let reduce_op = &reduce_op;
+    pi.try_fold(|| None::<R::Ok>,
+                move |acc, item: R| -> Result<Option<R::Ok>, R::Error> {
+                    match item.into_result() {
+                        Ok(b) => {
+                            match acc {
+                                Some(a) => reduce_op(a, b).into_result().map(Some),
+                                None => Ok(Some(b)),
+                            }
+                        }
+                        Err(e) => Err(e),
+                    }
+                })
+        .reduce(|| Ok(None),
+                move |left, right| match (left, right) {
+                    (Ok(Some(a)), Ok(Some(b))) => reduce_op(a, b).into_result().map(Some),
+                    (Ok(Some(v)), Ok(None)) | (Ok(None), Ok(Some(v))) => Ok(Some(v)),
+                    (Ok(None), Ok(None)) => Ok(None),
+                    (Err(e), _) | (_, Err(e)) => Err(e),
+                })
+        .map_or_else(|e| Some(R::from_error(e)), |opt| opt.map(R::from_ok))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn check_try_reduce_ok() {
+        let result: Option<i32> = vec![Some(1), Some(2), Some(3)].into_par_iter()
+            .try_reduce(|| 0, |a, b| Some(a + b));
+        assert_eq!(result, Some(6));
+    }
+
+    #[test]
+    fn check_try_reduce_short_circuits() {
+        let result: Option<i32> = vec![Some(1), None, Some(3)].into_par_iter()
+            .try_reduce(|| 0, |a, b| Some(a + b));
+        assert_eq!(result, None);
+    }
+
+    #[test]
+    fn check_try_reduce_with_empty() {
+        let v: Vec<Option<i32>> = vec![];
+        let result = v.into_par_iter().try_reduce_with(|a, b| Some(a + b));
+        assert_eq!(result, None);
+    }
+
+    #[test]
+    fn check_try_reduce_with_ok() {
+        let result = vec![Some(1), Some(2), Some(3)].into_par_iter()
+            .try_reduce_with(|a, b| Some(a + b));
+        assert_eq!(result, Some(Some(6)));
+    }
+
+    #[test]
+    fn check_try_reduce_with_short_circuits() {
+        let result = vec![Some(1), None, Some(3)].into_par_iter()
+            .try_reduce_with(|a, b| Some(a + b));
+        assert_eq!(result, Some(None));
+    }
+}